@@ -1,8 +1,9 @@
 mod test;
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::iter::Peekable;
+use std::ops::Index;
 use std::str::Chars;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -11,86 +12,299 @@ use std::str::Chars;
 pub enum JsonValue {
     Null,
     Boolean(bool),
-    Number(f64),
+    Integer(i64),
+    Float(f64),
     String(String),
     Array(Vec<JsonValue>),
-    Object(HashMap<String, JsonValue>),
+    Object(BTreeMap<String, JsonValue>),
 }
 
-//display trait to print JSON values as strings
+//display trait to print JSON values as strings; delegates to the compact serializer
+//so Display and to_json_string() can never disagree
 impl fmt::Display for JsonValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_json_string())
+    }
+}
+
+impl JsonValue {
+    /// Serializes this value to a compact, conformant JSON string.
+    ///
+    /// Named `to_json_string` rather than `to_string` so it doesn't shadow the
+    /// blanket `ToString` impl that `Display` already provides for this type.
+    pub fn to_json_string(&self) -> String {
+        let mut out = String::new();
+        self.write_compact(&mut out);
+        out
+    }
+
+    /// Serializes this value to an indented JSON string, using `indent` spaces per nesting level.
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_compact(&self, out: &mut String) {
         match self {
-            JsonValue::Null => f.write_str("null"),
-            JsonValue::Boolean(b) => b.fmt(f),
-            JsonValue::Number(n) => n.fmt(f),
-            JsonValue::String(s) => write!(f, "\"{}\"", s),
+            JsonValue::Null => out.push_str("null"),
+            JsonValue::Boolean(b) => out.push_str(if *b { "true" } else { "false" }),
+            JsonValue::Integer(n) => out.push_str(&n.to_string()),
+            JsonValue::Float(n) => out.push_str(&format_number(*n)),
+            JsonValue::String(s) => write_escaped_string(s, out),
             JsonValue::Array(arr) => {
-                if arr.is_empty() {
-                    f.write_str("[]")
-                } else {
-                    f.write_str("[")?;
-                    for (i, val) in arr.iter().enumerate() {
-                        if i > 0 {
-                            f.write_str(", ")?;
-                        }
-                        val.fmt(f)?;
+                out.push('[');
+                for (i, val) in arr.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
                     }
-                    f.write_str("]")
+                    val.write_compact(out);
                 }
+                out.push(']');
             }
             JsonValue::Object(obj) => {
-                if obj.is_empty() {
-                    f.write_str("{}")
-                } else {
-                    f.write_str("{")?;
-                    for (i, (key, val)) in obj.iter().enumerate() {
-                        if i > 0 {
-                            f.write_str(", ")?;
-                        }
-                        write!(f, "\"{}\": ", key)?;
-                        val.fmt(f)?;
+                out.push('{');
+                for (i, (key, val)) in obj.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
                     }
-                    f.write_str("}")
+                    write_escaped_string(key, out);
+                    out.push(':');
+                    val.write_compact(out);
                 }
+                out.push('}');
             }
         }
     }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, level: usize) {
+        match self {
+            JsonValue::Array(arr) if !arr.is_empty() => {
+                out.push_str("[\n");
+                for (i, val) in arr.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    push_indent(out, indent, level + 1);
+                    val.write_pretty(out, indent, level + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent, level);
+                out.push(']');
+            }
+            JsonValue::Object(obj) if !obj.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, val)) in obj.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    push_indent(out, indent, level + 1);
+                    write_escaped_string(key, out);
+                    out.push_str(": ");
+                    val.write_pretty(out, indent, level + 1);
+                }
+                out.push('\n');
+                push_indent(out, indent, level);
+                out.push('}');
+            }
+            // Null, booleans, numbers, strings, and empty arrays/objects have no
+            // nesting to indent, so the compact form is already correct.
+            _ => self.write_compact(out),
+        }
+    }
 }
 
-//custom error type for JSON parsing errors
-#[derive(Debug, Clone)]
-pub struct JsonError(String);
+// Shared sentinel returned by the `Index` impls for missing keys/indices, so lookups
+// can chain (`value["a"]["b"]`) without panicking on a document that doesn't match.
+static NULL: JsonValue = JsonValue::Null;
 
-impl fmt::Display for JsonError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+impl JsonValue {
+    /// Looks up a key in this value if it is an object, returning `None` otherwise
+    /// (including when the key is absent).
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            JsonValue::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            JsonValue::Integer(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Integer(n) => Some(*n as f64),
+            JsonValue::Float(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(arr) => Some(arr),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&BTreeMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(obj) => Some(obj),
+            _ => None,
+        }
+    }
+}
+
+// Indexing by key never panics: a non-object value or a missing key both yield
+// the shared `Null` sentinel, so chained lookups like `v["a"]["b"]` are safe.
+impl Index<&str> for JsonValue {
+    type Output = JsonValue;
+
+    fn index(&self, key: &str) -> &JsonValue {
+        self.get(key).unwrap_or(&NULL)
+    }
+}
+
+// Indexing by position never panics: a non-array value or an out-of-bounds index
+// both yield the shared `Null` sentinel.
+impl Index<usize> for JsonValue {
+    type Output = JsonValue;
+
+    fn index(&self, index: usize) -> &JsonValue {
+        match self.as_array().and_then(|arr| arr.get(index)) {
+            Some(value) => value,
+            None => &NULL,
+        }
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize, level: usize) {
+    for _ in 0..(indent * level) {
+        out.push(' ');
     }
 }
 
-impl PartialEq for JsonError {
-    fn eq(&self, _other: &JsonError) -> bool {
-        true
+// Formats a float so it always round-trips as a JSON number (never bare like an integer).
+fn format_number(n: f64) -> String {
+    let s = n.to_string();
+    if s.contains('.') || s.contains('e') || s.contains('E') {
+        s
+    } else {
+        format!("{}.0", s)
     }
 }
 
-impl Eq for JsonError {}
+// Writes `s` as a quoted JSON string, escaping control characters per RFC 8259.
+fn write_escaped_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+//custom error type for JSON parsing errors, carrying the position where it occurred
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {} column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
 
 #[derive(Debug)]
 struct Parser<'a> {
     chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+    offset: usize,
 }
 
 impl<'a> Parser<'a> {
     fn new(input: &'a str) -> Self {
         Parser {
             chars: input.chars().peekable(),
+            line: 1,
+            column: 1,
+            offset: 0,
         }
     }
+
+    // Builds a JsonError at the parser's current position.
+    fn error(&self, message: impl Into<String>) -> JsonError {
+        JsonError {
+            message: message.into(),
+            line: self.line,
+            column: self.column,
+            offset: self.offset,
+        }
+    }
+
+    // Consumes and returns the next char, advancing line/column/offset tracking.
+    fn advance(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
     fn skip_whitespace(&mut self) {
-        while let Some(&c) = self.chars.peek() {
+        while let Some(c) = self.peek() {
             if c.is_whitespace() {
-                self.chars.next();
+                self.advance();
             } else {
                 break;
             }
@@ -105,9 +319,9 @@ impl<'a> Parser<'a> {
     //parse any JSON value based on the first character
     fn parse_value(&mut self) -> Result<JsonValue, JsonError> {
         self.skip_whitespace();
-        let c = match self.chars.peek() {
-            Some(c) => *c,
-            None => return Err(JsonError(String::from("Unexpected end of input"))),
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error("Unexpected end of input")),
         };
         let result = match c {
             '{' => self.parse_object(),
@@ -117,22 +331,22 @@ impl<'a> Parser<'a> {
             'f' => self.parse_false(),
             'n' => self.parse_null(),
             '-' | '0'..='9' => self.parse_number(),
-            _ => Err(JsonError(format!("Unexpected character: {}", c))),
+            _ => Err(self.error(format!("Unexpected character: {}", c))),
         };
         result
     }
 
     //parse a JSON object
     fn parse_object(&mut self) -> Result<JsonValue, JsonError> {
-        match self.chars.next() {
+        match self.advance() {
             Some('{') => {}
-            _ => return Err(JsonError(String::from("Expected '{' at start of object"))),
+            _ => return Err(self.error("Expected '{' at start of object")),
         }
         self.skip_whitespace();
 
-        let mut map = HashMap::new();
-        if let Some('}') = self.chars.peek() {
-            self.chars.next();
+        let mut map = BTreeMap::new();
+        if let Some('}') = self.peek() {
+            self.advance();
             return Ok(JsonValue::Object(map));
         }
         loop {
@@ -141,26 +355,22 @@ impl<'a> Parser<'a> {
                 Ok(k) => k,
                 Err(e) => return Err(e),
             };
-            match self.chars.next() {
+            match self.advance() {
                 Some(':') => {}
-                _ => return Err(JsonError(String::from("Expected ':' after object key"))),
+                _ => return Err(self.error("Expected ':' after object key")),
             }
             let value = self.parse_value()?;
             map.insert(key, value);
             self.skip_whitespace();
 
-            match self.chars.next() {
+            match self.advance() {
                 Some(',') => {
                     continue;
                 }
                 Some('}') => {
                     break;
                 }
-                _ => {
-                    return Err(JsonError(String::from(
-                        "Expected ',' or '}' after object value",
-                    )))
-                }
+                _ => return Err(self.error("Expected ',' or '}' after object value")),
             }
         }
 
@@ -168,32 +378,28 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_array(&mut self) -> Result<JsonValue, JsonError> {
-        match self.chars.next() {
+        match self.advance() {
             Some('[') => {}
-            _ => return Err(JsonError(String::from("Expected '[' at start of array"))),
+            _ => return Err(self.error("Expected '[' at start of array")),
         }
         self.skip_whitespace();
         let mut array = Vec::new();
-        if let Some(']') = self.chars.peek() {
-            self.chars.next();
+        if let Some(']') = self.peek() {
+            self.advance();
             return Ok(JsonValue::Array(array));
         }
         loop {
             let value = self.parse_value()?;
             array.push(value);
             self.skip_whitespace();
-            match self.chars.next() {
+            match self.advance() {
                 Some(',') => {
                     continue;
                 }
                 Some(']') => {
                     break;
                 }
-                _ => {
-                    return Err(JsonError(String::from(
-                        "Expected ',' or ']' after array element",
-                    )))
-                }
+                _ => return Err(self.error("Expected ',' or ']' after array element")),
             }
         }
 
@@ -201,18 +407,18 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_string(&mut self) -> Result<String, JsonError> {
-        match self.chars.next() {
+        match self.advance() {
             Some('"') => {}
-            _ => return Err(JsonError(String::from("Expected '\"' at start of string"))),
+            _ => return Err(self.error("Expected '\"' at start of string")),
         }
         let mut result = String::new();
         loop {
-            match self.chars.next() {
+            match self.advance() {
                 Some('"') => {
                     break;
                 }
                 //unicode escape seqeunces
-                Some('\\') => match self.chars.next() {
+                Some('\\') => match self.advance() {
                     Some('"') => result.push('"'),
                     Some('\\') => result.push('\\'),
                     Some('/') => result.push('/'),
@@ -221,40 +427,91 @@ impl<'a> Parser<'a> {
                     Some('n') => result.push('\n'),
                     Some('r') => result.push('\r'),
                     Some('t') => result.push('\t'),
-                    _ => return Err(JsonError(String::from("Invalid escape sequence"))),
+                    Some('u') => {
+                        let code_point = self.parse_unicode_escape()?;
+                        let c = if (0xD800..=0xDBFF).contains(&code_point) {
+                            match self.advance() {
+                                Some('\\') => {}
+                                _ => {
+                                    return Err(
+                                        self.error("Expected low surrogate after high surrogate")
+                                    )
+                                }
+                            }
+                            match self.advance() {
+                                Some('u') => {}
+                                _ => {
+                                    return Err(
+                                        self.error("Expected low surrogate after high surrogate")
+                                    )
+                                }
+                            }
+                            let low = self.parse_unicode_escape()?;
+                            if !(0xDC00..=0xDFFF).contains(&low) {
+                                return Err(self.error("Invalid low surrogate in unicode escape"));
+                            }
+                            let combined =
+                                0x10000 + ((code_point - 0xD800) << 10) + (low - 0xDC00);
+                            char::from_u32(combined)
+                        } else if (0xDC00..=0xDFFF).contains(&code_point) {
+                            return Err(self.error("Unexpected low surrogate in unicode escape"));
+                        } else {
+                            char::from_u32(code_point)
+                        };
+                        match c {
+                            Some(c) => result.push(c),
+                            None => return Err(self.error("Invalid unicode escape sequence")),
+                        }
+                    }
+                    _ => return Err(self.error("Invalid escape sequence")),
                 },
                 Some(c) => {
                     result.push(c);
                 }
-                None => return Err(JsonError(String::from("Unterminated string"))),
+                None => return Err(self.error("Unterminated string")),
             }
         }
 
         Ok(result)
     }
 
+    // Reads exactly four hex digits following a `\u` escape and returns the code unit.
+    fn parse_unicode_escape(&mut self) -> Result<u32, JsonError> {
+        let mut value: u32 = 0;
+        for _ in 0..4 {
+            let digit = match self.advance() {
+                Some(c) => c
+                    .to_digit(16)
+                    .ok_or_else(|| self.error("Invalid hex digit in unicode escape"))?,
+                None => return Err(self.error("Unexpected end of input in unicode escape")),
+            };
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
     fn parse_number(&mut self) -> Result<JsonValue, JsonError> {
         let mut number_str = String::new();
-        if let Some('-') = self.chars.peek() {
+        if let Some('-') = self.peek() {
             number_str.push('-');
-            self.chars.next();
+            self.advance();
         }
         let mut has_digits = false;
-        if let Some('0') = self.chars.peek() {
+        if let Some('0') = self.peek() {
             number_str.push('0');
-            self.chars.next();
+            self.advance();
             has_digits = true;
         } else {
-            match self.chars.peek() {
-                Some(&c) if c.is_ascii_digit() => {
+            match self.peek() {
+                Some(c) if c.is_ascii_digit() => {
                     number_str.push(c);
-                    self.chars.next();
+                    self.advance();
                     has_digits = true;
 
-                    while let Some(&c) = self.chars.peek() {
+                    while let Some(c) = self.peek() {
                         if c.is_ascii_digit() {
                             number_str.push(c);
-                            self.chars.next();
+                            self.advance();
                         } else {
                             break;
                         }
@@ -265,18 +522,21 @@ impl<'a> Parser<'a> {
         }
 
         if !has_digits {
-            return Err(JsonError(String::from("Expected digit in number")));
+            return Err(self.error("Expected digit in number"));
         }
 
-        if let Some('.') = self.chars.peek() {
+        let mut is_integer = true;
+
+        if let Some('.') = self.peek() {
+            is_integer = false;
             number_str.push('.');
-            self.chars.next();
+            self.advance();
 
             let mut has_decimal_digits = false;
-            while let Some(&c) = self.chars.peek() {
+            while let Some(c) = self.peek() {
                 if c.is_ascii_digit() {
                     number_str.push(c);
-                    self.chars.next();
+                    self.advance();
                     has_decimal_digits = true;
                 } else {
                     break;
@@ -284,26 +544,25 @@ impl<'a> Parser<'a> {
             }
 
             if !has_decimal_digits {
-                return Err(JsonError(String::from(
-                    "Expected digit after decimal point",
-                )));
+                return Err(self.error("Expected digit after decimal point"));
             }
         }
-        if let Some(&c) = self.chars.peek() {
+        if let Some(c) = self.peek() {
             if c == 'e' || c == 'E' {
+                is_integer = false;
                 number_str.push(c);
-                self.chars.next();
-                if let Some(&c) = self.chars.peek() {
+                self.advance();
+                if let Some(c) = self.peek() {
                     if c == '+' || c == '-' {
                         number_str.push(c);
-                        self.chars.next();
+                        self.advance();
                     }
                 }
                 let mut has_exp_digits = false;
-                while let Some(&c) = self.chars.peek() {
+                while let Some(c) = self.peek() {
                     if c.is_ascii_digit() {
                         number_str.push(c);
-                        self.chars.next();
+                        self.advance();
                         has_exp_digits = true;
                     } else {
                         break;
@@ -311,13 +570,24 @@ impl<'a> Parser<'a> {
                 }
 
                 if !has_exp_digits {
-                    return Err(JsonError(String::from("Expected digit in exponent")));
+                    return Err(self.error("Expected digit in exponent"));
                 }
             }
         }
+        // Prefer the narrower integer representation so IDs beyond 2^53 keep full
+        // precision and `1` round-trips distinctly from `1.0`; only fall back to
+        // f64 when the literal has a fractional/exponent part or overflows i64.
+        if is_integer {
+            if let Ok(n) = number_str.parse::<i64>() {
+                return Ok(JsonValue::Integer(n));
+            }
+        }
         match number_str.parse::<f64>() {
-            Ok(n) => Ok(JsonValue::Number(n)),
-            Err(_) => Err(JsonError(format!("Invalid number: {}", number_str))),
+            // A literal like "1e400" parses "successfully" as `f64::INFINITY` rather
+            // than erroring, but `inf`/`-inf`/`NaN` have no valid JSON representation,
+            // so treat that overflow the same as a parse failure.
+            Ok(n) if n.is_finite() => Ok(JsonValue::Float(n)),
+            _ => Err(self.error(format!("Invalid number: {}", number_str))),
         }
     }
 
@@ -325,7 +595,7 @@ impl<'a> Parser<'a> {
         if self.consume_literal("true") {
             Ok(JsonValue::Boolean(true))
         } else {
-            Err(JsonError(String::from("Expected true")))
+            Err(self.error("Expected true"))
         }
     }
 
@@ -333,7 +603,7 @@ impl<'a> Parser<'a> {
         if self.consume_literal("false") {
             Ok(JsonValue::Boolean(false))
         } else {
-            Err(JsonError(String::from("Expected false")))
+            Err(self.error("Expected false"))
         }
     }
 
@@ -341,7 +611,7 @@ impl<'a> Parser<'a> {
         if self.consume_literal("null") {
             Ok(JsonValue::Null)
         } else {
-            Err(JsonError(String::from("Expected null")))
+            Err(self.error("Expected null"))
         }
     }
 
@@ -352,7 +622,7 @@ impl<'a> Parser<'a> {
         let mut input_matches = true;
 
         for expected in chars {
-            match self.chars.next() {
+            match self.advance() {
                 Some(c) if c == expected => {
                     continue;
                 }
@@ -370,3 +640,201 @@ pub fn parse(input: &str) -> Result<JsonValue, JsonError> {
     let mut parser = Parser::new(input);
     parser.parse()
 }
+
+/// The default nesting limit for [`StreamingParser`], chosen to comfortably clear
+/// realistic documents while still bounding heap growth on adversarial input.
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+/// One token of a JSON document as produced by [`StreamingParser`].
+///
+/// Object and array boundaries are reported separately from their contents so a
+/// caller can process deeply nested or huge documents without ever materializing
+/// more than one value at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonEvent {
+    ObjectStart,
+    ObjectEnd,
+    ArrayStart,
+    ArrayEnd,
+    Key(String),
+    Value(JsonValue),
+}
+
+// Tracks where in its enclosing object/array the parser currently is, so that
+// container state lives on an explicit stack instead of the call stack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frame {
+    ArrayStart,
+    ArrayAfterComma,
+    ArrayAfterValue,
+    ObjectStart,
+    ObjectAfterComma,
+    ObjectAfterKey,
+    ObjectAfterValue,
+}
+
+/// A pull parser that yields [`JsonEvent`]s instead of building a [`JsonValue`] tree.
+///
+/// Nesting is tracked with an explicit `Vec<Frame>` stack rather than recursion, so
+/// document depth is bounded only by heap (and by `max_depth`, which defaults to
+/// [`DEFAULT_MAX_DEPTH`]), and a caller can stop pulling at any point without having
+/// parsed the rest of the document.
+pub struct StreamingParser<'a> {
+    parser: Parser<'a>,
+    stack: Vec<Frame>,
+    max_depth: usize,
+    done: bool,
+}
+
+impl<'a> StreamingParser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self::with_max_depth(input, DEFAULT_MAX_DEPTH)
+    }
+
+    pub fn with_max_depth(input: &'a str, max_depth: usize) -> Self {
+        StreamingParser {
+            parser: Parser::new(input),
+            stack: Vec::new(),
+            max_depth,
+            done: false,
+        }
+    }
+
+    fn next_event(&mut self) -> Result<Option<JsonEvent>, JsonError> {
+        if self.stack.is_empty() {
+            self.parser.skip_whitespace();
+            if self.parser.peek().is_none() {
+                return Ok(None);
+            }
+            return Ok(Some(self.read_value()?));
+        }
+
+        match *self.stack.last().expect("stack checked non-empty above") {
+            Frame::ArrayStart | Frame::ArrayAfterComma => {
+                self.parser.skip_whitespace();
+                if matches!(self.stack.last(), Some(Frame::ArrayStart)) {
+                    if let Some(']') = self.parser.peek() {
+                        self.parser.advance();
+                        self.stack.pop();
+                        return Ok(Some(JsonEvent::ArrayEnd));
+                    }
+                }
+                *self.stack.last_mut().unwrap() = Frame::ArrayAfterValue;
+                Ok(Some(self.read_value()?))
+            }
+            Frame::ArrayAfterValue => {
+                self.parser.skip_whitespace();
+                match self.parser.advance() {
+                    Some(']') => {
+                        self.stack.pop();
+                        Ok(Some(JsonEvent::ArrayEnd))
+                    }
+                    Some(',') => {
+                        *self.stack.last_mut().unwrap() = Frame::ArrayAfterComma;
+                        self.next_event()
+                    }
+                    _ => Err(self.parser.error("Expected ',' or ']' after array element")),
+                }
+            }
+            Frame::ObjectStart | Frame::ObjectAfterComma => {
+                self.parser.skip_whitespace();
+                if matches!(self.stack.last(), Some(Frame::ObjectStart)) {
+                    if let Some('}') = self.parser.peek() {
+                        self.parser.advance();
+                        self.stack.pop();
+                        return Ok(Some(JsonEvent::ObjectEnd));
+                    }
+                }
+                let key = self.parser.parse_string()?;
+                self.parser.skip_whitespace();
+                match self.parser.advance() {
+                    Some(':') => {}
+                    _ => return Err(self.parser.error("Expected ':' after object key")),
+                }
+                *self.stack.last_mut().unwrap() = Frame::ObjectAfterKey;
+                Ok(Some(JsonEvent::Key(key)))
+            }
+            Frame::ObjectAfterKey => {
+                *self.stack.last_mut().unwrap() = Frame::ObjectAfterValue;
+                Ok(Some(self.read_value()?))
+            }
+            Frame::ObjectAfterValue => {
+                self.parser.skip_whitespace();
+                match self.parser.advance() {
+                    Some('}') => {
+                        self.stack.pop();
+                        Ok(Some(JsonEvent::ObjectEnd))
+                    }
+                    Some(',') => {
+                        *self.stack.last_mut().unwrap() = Frame::ObjectAfterComma;
+                        self.next_event()
+                    }
+                    _ => Err(self.parser.error("Expected ',' or '}' after object value")),
+                }
+            }
+        }
+    }
+
+    // Reads one value at the current position: a scalar is returned directly as a
+    // `Value` event, while an object/array pushes a new frame and returns its
+    // `*Start` event, leaving the contents for subsequent calls.
+    fn read_value(&mut self) -> Result<JsonEvent, JsonError> {
+        self.parser.skip_whitespace();
+        let c = match self.parser.peek() {
+            Some(c) => c,
+            None => return Err(self.parser.error("Unexpected end of input")),
+        };
+        match c {
+            '{' => {
+                self.push_frame(Frame::ObjectStart)?;
+                self.parser.advance();
+                Ok(JsonEvent::ObjectStart)
+            }
+            '[' => {
+                self.push_frame(Frame::ArrayStart)?;
+                self.parser.advance();
+                Ok(JsonEvent::ArrayStart)
+            }
+            '"' => self
+                .parser
+                .parse_string()
+                .map(|s| JsonEvent::Value(JsonValue::String(s))),
+            't' => self.parser.parse_true().map(JsonEvent::Value),
+            'f' => self.parser.parse_false().map(JsonEvent::Value),
+            'n' => self.parser.parse_null().map(JsonEvent::Value),
+            '-' | '0'..='9' => self.parser.parse_number().map(JsonEvent::Value),
+            _ => Err(self.parser.error(format!("Unexpected character: {}", c))),
+        }
+    }
+
+    fn push_frame(&mut self, frame: Frame) -> Result<(), JsonError> {
+        if self.stack.len() >= self.max_depth {
+            return Err(self
+                .parser
+                .error(format!("Exceeded maximum nesting depth of {}", self.max_depth)));
+        }
+        self.stack.push(frame);
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for StreamingParser<'a> {
+    type Item = Result<JsonEvent, JsonError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_event() {
+            Ok(Some(event)) => Some(Ok(event)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -1,13 +1,13 @@
-use crate::{parse,JsonValue};
-use std::collections::HashMap;
+use crate::{parse, JsonEvent, JsonValue, StreamingParser};
+use std::collections::BTreeMap;
 
 #[test]
 fn test_basic_values() {
     assert_eq!(parse("null"), Ok(JsonValue::Null));
     assert_eq!(parse("true"), Ok(JsonValue::Boolean(true)));
     assert_eq!(parse("false"), Ok(JsonValue::Boolean(false)));
-    assert_eq!(parse("123"), Ok(JsonValue::Number(123.0)));
-    assert_eq!(parse("-45.67"), Ok(JsonValue::Number(-45.67)));
+    assert_eq!(parse("123"), Ok(JsonValue::Integer(123)));
+    assert_eq!(parse("-45.67"), Ok(JsonValue::Float(-45.67)));
 }
 
 #[test]
@@ -27,20 +27,20 @@ fn test_arrays() {
     assert_eq!(parse("[]"), Ok(JsonValue::Array(vec![])));
 
     let expected = JsonValue::Array(vec![
-        JsonValue::Number(1.0),
-        JsonValue::Number(2.0),
-        JsonValue::Number(3.0),
+        JsonValue::Integer(1),
+        JsonValue::Integer(2),
+        JsonValue::Integer(3),
     ]);
     assert_eq!(parse("[1, 2, 3]"), Ok(expected));
 }
 
 #[test]
 fn test_objects() {
-    assert_eq!(parse("{}"), Ok(JsonValue::Object(HashMap::new())));
+    assert_eq!(parse("{}"), Ok(JsonValue::Object(BTreeMap::new())));
 
-    let mut map = HashMap::new();
+    let mut map = BTreeMap::new();
     map.insert("name".to_string(), JsonValue::String("John".to_string()));
-    map.insert("age".to_string(), JsonValue::Number(30.0));
+    map.insert("age".to_string(), JsonValue::Integer(30));
 
     assert_eq!(
         parse("{\"name\": \"John\", \"age\": 30}"),
@@ -60,6 +60,148 @@ fn test_nested_structures() {
     }
 }
 
+#[test]
+fn test_unicode_escapes() {
+    assert_eq!(parse("\"\\u00e9\""), Ok(JsonValue::String("é".to_string())));
+    assert_eq!(
+        parse("\"\\ud83d\\ude00\""),
+        Ok(JsonValue::String("😀".to_string()))
+    );
+    assert!(parse("\"\\ud83d\"").is_err());
+    assert!(parse("\"\\u12\"").is_err());
+    assert!(parse("\"\\uzzzz\"").is_err());
+}
+
+#[test]
+fn test_to_string_compact() {
+    let value = parse(r#"{"b": 1.5, "a": [true, null, "x\"y"]}"#).unwrap();
+    assert_eq!(value.to_string(), r#"{"a":[true,null,"x\"y"],"b":1.5}"#);
+}
+
+#[test]
+fn test_to_json_string_matches_display() {
+    let value = parse(r#"{"a": 1}"#).unwrap();
+    assert_eq!(value.to_json_string(), value.to_string());
+    assert_eq!(value.to_json_string(), r#"{"a":1}"#);
+}
+
+#[test]
+fn test_to_string_pretty() {
+    let value = parse(r#"{"a": [1, 2]}"#).unwrap();
+    assert_eq!(value.to_string_pretty(2), "{\n  \"a\": [\n    1,\n    2\n  ]\n}");
+}
+
+#[test]
+fn test_to_string_escapes_control_characters() {
+    let value = JsonValue::String("line\nbreak\tand\u{0007}bell".to_string());
+    assert_eq!(value.to_string(), "\"line\\nbreak\\tand\\u0007bell\"");
+}
+
+#[test]
+fn test_error_position() {
+    let err = parse("{\n  \"key\" 1\n}").unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(err.column, 9);
+    assert_eq!(
+        err.to_string(),
+        "parse error at line 2 column 9: Expected ':' after object key"
+    );
+}
+
+#[test]
+fn test_error_equality_compares_position() {
+    let a = parse("{").unwrap_err();
+    let b = parse("[").unwrap_err();
+    assert_ne!(a, b);
+    assert_eq!(parse("{").unwrap_err(), parse("{").unwrap_err());
+}
+
+#[test]
+fn test_streaming_parser_events() {
+    let json = r#"{"a": [1, "two"], "b": null}"#;
+    let events: Result<Vec<JsonEvent>, _> = StreamingParser::new(json).collect();
+    assert_eq!(
+        events.unwrap(),
+        vec![
+            JsonEvent::ObjectStart,
+            JsonEvent::Key("a".to_string()),
+            JsonEvent::ArrayStart,
+            JsonEvent::Value(JsonValue::Integer(1)),
+            JsonEvent::Value(JsonValue::String("two".to_string())),
+            JsonEvent::ArrayEnd,
+            JsonEvent::Key("b".to_string()),
+            JsonEvent::Value(JsonValue::Null),
+            JsonEvent::ObjectEnd,
+        ]
+    );
+}
+
+#[test]
+fn test_streaming_parser_reports_syntax_errors() {
+    let mut events = StreamingParser::new("[1, 2,]");
+    assert!(events.next().unwrap().is_ok()); // ArrayStart
+    assert!(events.next().unwrap().is_ok()); // 1
+    assert!(events.next().unwrap().is_ok()); // 2
+    assert!(events.next().unwrap().is_err());
+    assert!(events.next().is_none());
+}
+
+#[test]
+fn test_streaming_parser_max_depth() {
+    let mut events = StreamingParser::with_max_depth("[[[1]]]", 2);
+    assert!(events.next().unwrap().is_ok()); // outer ArrayStart
+    assert!(events.next().unwrap().is_ok()); // middle ArrayStart
+    assert!(events.next().unwrap().is_err()); // inner array exceeds depth
+}
+
+#[test]
+fn test_index_and_accessors() {
+    let json = r#"{"data": {"users": [{"id": 1, "name": "Alice"}]}}"#;
+    let parsed = parse(json).unwrap();
+
+    assert_eq!(parsed["data"]["users"][0]["name"].as_str(), Some("Alice"));
+    assert_eq!(parsed["data"]["users"][0]["id"].as_f64(), Some(1.0));
+    assert_eq!(parsed["data"]["users"][0]["missing"], JsonValue::Null);
+    assert_eq!(parsed["data"]["users"][99], JsonValue::Null);
+    assert_eq!(parsed["nope"]["still_nope"], JsonValue::Null);
+
+    assert!(parsed["data"].as_object().is_some());
+    assert!(parsed["data"]["users"].as_array().is_some());
+    assert_eq!(parsed["data"]["users"][0]["id"].as_bool(), None);
+}
+
+#[test]
+fn test_integer_and_float_numbers() {
+    assert_eq!(parse("0"), Ok(JsonValue::Integer(0)));
+    assert_eq!(parse("-17"), Ok(JsonValue::Integer(-17)));
+    assert_eq!(parse("1.0"), Ok(JsonValue::Float(1.0)));
+    assert_eq!(parse("1e3"), Ok(JsonValue::Float(1000.0)));
+
+    // Beyond i64 range but syntactically an integer literal: falls back to f64.
+    assert_eq!(parse("99999999999999999999"), Ok(JsonValue::Float(1e20)));
+
+    // A large integer that fits in i64 keeps full precision, unlike an f64 would.
+    assert_eq!(
+        parse("9007199254740993"),
+        Ok(JsonValue::Integer(9007199254740993))
+    );
+
+    assert_eq!(JsonValue::Integer(5).as_i64(), Some(5));
+    assert_eq!(JsonValue::Integer(5).as_u64(), Some(5));
+    assert_eq!(JsonValue::Integer(-5).as_u64(), None);
+    assert_eq!(JsonValue::Integer(5).to_string(), "5");
+    assert_eq!(JsonValue::Float(5.0).to_string(), "5.0");
+}
+
+#[test]
+fn test_rejects_non_finite_numbers() {
+    // The f64 parse of "1e400" silently overflows to infinity rather than
+    // erroring, so this must be rejected explicitly to avoid serializing
+    // invalid JSON like "inf.0" later.
+    assert!(parse("1e400").is_err());
+    assert!(parse("-1e400").is_err());
+}
+
 #[test]
 fn test_errors() {
     assert!(parse("{").is_err());